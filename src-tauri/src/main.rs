@@ -2,6 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, fs, path::PathBuf, process::Command};
+#[cfg(target_os = "linux")]
+use std::sync::OnceLock;
+
+#[cfg(target_os = "linux")]
+static ORIGINAL_ENV: OnceLock<HashMap<String, String>> = OnceLock::new();
 
 #[derive(Serialize, Deserialize)]
 struct Profile {
@@ -10,9 +15,19 @@ struct Profile {
     email: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+enum Browser {
+    Chrome,
+    Edge,
+    Brave,
+    Chromium,
+    Vivaldi,
+}
+
 #[tauri::command]
-fn get_profiles() -> Result<Vec<Profile>, String> {
-    let mut path = get_chrome_user_data_dir()?;
+fn get_profiles(browser: Browser) -> Result<Vec<Profile>, String> {
+    let mut path = get_chrome_user_data_dir(browser)?;
     path.push("Local State");
 
     let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
@@ -35,17 +50,360 @@ fn get_profiles() -> Result<Vec<Profile>, String> {
 }
 
 #[tauri::command]
-fn launch_profile(folder: String) -> Result<(), String> {
-    let chrome_path = get_chrome_executable()?;
+fn launch_profile(browser: Browser, folder: String) -> Result<(), String> {
+    let chrome_path = get_chrome_executable(browser)?;
+
+    let mut command = Command::new(chrome_path);
+    command.arg(format!("--profile-directory={}", folder));
 
-    Command::new(chrome_path)
+    #[cfg(target_os = "linux")]
+    {
+        command.env_clear().envs(normalized_spawn_env());
+    }
+
+    command.spawn().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+struct ProfileLaunchConfig {
+    urls: Vec<String>,
+    extra_args: Vec<String>,
+}
+
+#[tauri::command]
+fn launch_profile_with(
+    browser: Browser,
+    folder: String,
+    urls: Vec<String>,
+    extra_args: Vec<String>,
+) -> Result<(), String> {
+    let chrome_path = get_chrome_executable(browser)?;
+
+    let mut command = Command::new(chrome_path);
+    command
         .arg(format!("--profile-directory={}", folder))
+        .args(&extra_args)
+        .args(&urls);
+
+    #[cfg(target_os = "linux")]
+    {
+        command.env_clear().envs(normalized_spawn_env());
+    }
+
+    command.spawn().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+fn get_launch_configs() -> Result<HashMap<String, ProfileLaunchConfig>, String> {
+    let path = get_launch_configs_path();
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn save_launch_configs(configs: HashMap<String, ProfileLaunchConfig>) -> Result<(), String> {
+    let path = get_launch_configs_path();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let json = serde_json::to_string_pretty(&configs).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+fn get_launch_configs_path() -> PathBuf {
+    let mut path = dirs::home_dir().unwrap();
+    path.push(".chrome-launcher/launch_configs.json");
+    path
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "message")]
+enum DebugLaunchError {
+    Spawn(String),
+    NoAvailablePorts,
+    PortOpenTimeout,
+}
+
+const DEBUG_PORT_RANGE: std::ops::RangeInclusive<u16> = 9222..=9322;
+const DEBUG_PORT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[tauri::command]
+fn launch_profile_debug(
+    browser: Browser,
+    folder: String,
+    port: u16,
+) -> Result<String, DebugLaunchError> {
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::process::Stdio;
+    use std::sync::mpsc;
+
+    // Hold the listener open until Chrome has actually been spawned, so no other
+    // caller of this command (or unrelated process) can steal the port out from
+    // under us in the window between picking it and `Command::spawn()`.
+    let listener = if port == 0 {
+        reserve_available_port()?
+    } else {
+        TcpListener::bind(("127.0.0.1", port)).map_err(|_| DebugLaunchError::NoAvailablePorts)?
+    };
+    let port = listener
+        .local_addr()
+        .map_err(|e| DebugLaunchError::Spawn(e.to_string()))?
+        .port();
+
+    let chrome_path = get_chrome_executable(browser).map_err(DebugLaunchError::Spawn)?;
+
+    let mut command = Command::new(chrome_path);
+    command
+        .arg(format!("--profile-directory={}", folder))
+        .arg("--headless")
+        .arg(format!("--remote-debugging-port={}", port))
+        .stderr(Stdio::piped());
+
+    #[cfg(target_os = "linux")]
+    {
+        command.env_clear().envs(normalized_spawn_env());
+    }
+
+    let mut child = command
         .spawn()
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| DebugLaunchError::Spawn(e.to_string()))?;
+
+    // Chrome is about to bind the port itself now, so we can release ours.
+    drop(listener);
+
+    let stderr = match child.stderr.take() {
+        Some(stderr) => stderr,
+        None => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(DebugLaunchError::Spawn(
+                "Could not capture stderr".to_string(),
+            ));
+        }
+    };
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let devtools_url = regex::Regex::new(r"ws://\S+").unwrap();
+        for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+            if let Some(m) = devtools_url.find(&line) {
+                let _ = tx.send(m.as_str().to_string());
+                return;
+            }
+        }
+    });
+
+    match rx.recv_timeout(DEBUG_PORT_TIMEOUT) {
+        Ok(url) => Ok(url),
+        Err(_) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            Err(DebugLaunchError::PortOpenTimeout)
+        }
+    }
+}
+
+fn reserve_available_port() -> Result<std::net::TcpListener, DebugLaunchError> {
+    use std::net::TcpListener;
+
+    for port in DEBUG_PORT_RANGE {
+        if let Ok(listener) = TcpListener::bind(("127.0.0.1", port)) {
+            return Ok(listener);
+        }
+    }
+
+    Err(DebugLaunchError::NoAvailablePorts)
+}
+
+/// Strips the directories an AppImage/Flatpak/Snap bundle injects (`LD_LIBRARY_PATH`,
+/// `GTK_PATH`, `GST_PLUGIN_*`, `PATH`) out of the environment so the spawned Chrome
+/// loads host libraries instead of the bundle's, falling back to the environment
+/// captured at startup for anything that isn't a `PATH`-like list.
+#[cfg(target_os = "linux")]
+fn normalized_spawn_env() -> HashMap<String, String> {
+    const PATH_LIKE_VARS: &[&str] = &[
+        "PATH",
+        "LD_LIBRARY_PATH",
+        "GTK_PATH",
+        "GST_PLUGIN_PATH",
+        "GST_PLUGIN_SYSTEM_PATH",
+        "XDG_DATA_DIRS",
+    ];
+
+    let original = ORIGINAL_ENV.get_or_init(|| std::env::vars().collect());
+
+    let bundle_root = detect_bundle_root();
+
+    let Some(bundle_root) = bundle_root else {
+        return original.clone();
+    };
+
+    let mut cleaned = original.clone();
+
+    for (var, value) in original {
+        if !PATH_LIKE_VARS.contains(&var.as_str()) && !var.starts_with("GST_PLUGIN_") {
+            continue;
+        }
+
+        let entries: Vec<String> = value
+            .split(':')
+            .filter(|entry| {
+                *entry != bundle_root && !entry.starts_with(&format!("{bundle_root}/"))
+            })
+            .map(|entry| entry.to_string())
+            .collect();
+
+        let deduped = dedup_lowest_priority(entries);
+
+        if deduped.is_empty() {
+            cleaned.remove(var);
+        } else {
+            cleaned.insert(var.clone(), deduped.join(":"));
+        }
+    }
+
+    cleaned
+}
+
+/// Resolves the bundle's root directory from whichever of `APPDIR`, `APPIMAGE`,
+/// `SNAP`, or `FLATPAK_ID` is set, in that preference order. `APPIMAGE` holds the
+/// path to the `.AppImage` file itself rather than a mount point, so its parent
+/// directory is used as the best-effort root when `APPDIR` isn't present.
+#[cfg(target_os = "linux")]
+fn detect_bundle_root() -> Option<String> {
+    if let Ok(dir) = std::env::var("APPDIR") {
+        return Some(dir);
+    }
+
+    if let Ok(appimage) = std::env::var("APPIMAGE") {
+        if let Some(parent) = PathBuf::from(appimage).parent() {
+            return Some(parent.display().to_string());
+        }
+    }
+
+    if let Ok(snap) = std::env::var("SNAP") {
+        return Some(snap);
+    }
+
+    std::env::var("FLATPAK_ID").is_ok().then(|| "/app".to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn dedup_lowest_priority(entries: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    let mut result: Vec<String> = entries
+        .into_iter()
+        .rev()
+        .filter(|entry| seen.insert(entry.clone()))
+        .collect();
+    result.reverse();
+    result
+}
+
+#[tauri::command]
+fn create_profile_shortcut(browser: Browser, folder: String, name: String) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let _ = (browser, folder, name);
+        return Err("create_profile_shortcut is not supported on macOS yet".to_string());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let chrome_path = get_chrome_executable(browser)?;
+        let mut dir = dirs::data_local_dir().ok_or("Cannot find local data dir")?;
+        dir.push("applications");
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+        let sanitized_name: String = name
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect();
+
+        let exec = format!(
+            "{} {}",
+            desktop_quote(&chrome_path.display().to_string()),
+            desktop_quote(&format!("--profile-directory={}", folder)),
+        );
+
+        let mut path = dir;
+        path.push(format!("chrome-launcher-{}-{}.desktop", sanitized_name, folder));
 
+        let contents = format!(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name={name}\n\
+             Exec={exec}\n\
+             Icon={icon}\n\
+             Terminal=false\n\
+             StartupWMClass={wm_class}\n\
+             Categories=Network;WebBrowser;\n",
+            name = name,
+            exec = exec,
+            icon = browser.icon_name(),
+            wm_class = browser.wm_class(),
+        );
+
+        fs::write(path, contents).map_err(|e| e.to_string())?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let chrome_path = get_chrome_executable(browser)?;
+        let mut dir = dirs::data_dir().ok_or("Cannot find data dir")?;
+        dir.push(r"Microsoft\Windows\Start Menu\Programs");
+
+        let sanitized_name: String = name
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect();
+
+        let sanitized_folder: String = folder
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect();
+
+        let mut path = dir;
+        path.push(format!("{}-{}.lnk", sanitized_name, sanitized_folder));
+
+        let mut shortcut = mslnk::ShellLink::new(&chrome_path).map_err(|e| e.to_string())?;
+        shortcut.set_arguments(Some(format!("--profile-directory={}", folder)));
+        shortcut.set_name(Some(name));
+        shortcut
+            .create_lnk(&path)
+            .map_err(|e| e.to_string())?;
+    }
+
+    #[cfg(not(target_os = "macos"))]
     Ok(())
 }
 
+/// Escapes and double-quotes a single argument per the Desktop
+/// Entry Specification's `Exec` quoting rules. Always double-quotes the value
+/// rather than only doing so when it contains a space, since reserved
+/// characters like `;`, `&`, `|`, `~`, or `'` are unsafe unquoted too.
+#[cfg(target_os = "linux")]
+fn desktop_quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace('$', "\\$")
+        .replace('"', "\\\"");
+
+    format!("\"{}\"", escaped)
+}
+
 #[tauri::command]
 fn get_tags() -> Result<HashMap<String, Vec<String>>, String> {
     let path = get_tags_path();
@@ -69,18 +427,140 @@ fn save_tags(tags: HashMap<String, Vec<String>>) -> Result<(), String> {
     fs::write(path, json).map_err(|e| e.to_string())
 }
 
+#[cfg(target_os = "windows")]
+fn chrome_path_from_registry(browser: Browser) -> Option<PathBuf> {
+    use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+    use winreg::RegKey;
+
+    // Chromium ships as `chrome.exe` too, which is the same App Paths key Google
+    // Chrome registers. Trusting the registry here would resolve to whichever of
+    // the two is actually installed, not necessarily the one the caller asked
+    // for, so fall straight through to the candidate-path search instead.
+    if matches!(browser, Browser::Chromium) {
+        return None;
+    }
+
+    let app_paths_key = format!(
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\{}",
+        browser.exe_name()
+    );
+
+    for hive in [HKEY_LOCAL_MACHINE, HKEY_CURRENT_USER] {
+        if let Ok(key) = RegKey::predef(hive).open_subkey(&app_paths_key) {
+            if let Ok(path) = key.get_value::<String, _>("") {
+                let path = PathBuf::from(path);
+                if path.exists() {
+                    return Some(path);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+impl Browser {
+    #[cfg(target_os = "windows")]
+    fn exe_name(self) -> &'static str {
+        match self {
+            Browser::Chrome | Browser::Chromium => "chrome.exe",
+            Browser::Edge => "msedge.exe",
+            Browser::Brave => "brave.exe",
+            Browser::Vivaldi => "vivaldi.exe",
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn app_dir_name(self) -> &'static str {
+        match self {
+            Browser::Chrome => r"Google\Chrome",
+            Browser::Edge => r"Microsoft\Edge",
+            Browser::Brave => r"BraveSoftware\Brave-Browser",
+            Browser::Chromium => "Chromium",
+            Browser::Vivaldi => "Vivaldi",
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn config_dir_name(self) -> &'static str {
+        match self {
+            Browser::Chrome => ".config/google-chrome",
+            Browser::Edge => ".config/microsoft-edge",
+            Browser::Brave => ".config/BraveSoftware/Brave-Browser",
+            Browser::Chromium => ".config/chromium",
+            Browser::Vivaldi => ".config/vivaldi",
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn binary_name(self) -> &'static str {
+        match self {
+            Browser::Chrome => "google-chrome",
+            Browser::Edge => "microsoft-edge",
+            Browser::Brave => "brave-browser",
+            Browser::Chromium => "chromium",
+            Browser::Vivaldi => "vivaldi-stable",
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn icon_name(self) -> &'static str {
+        match self {
+            Browser::Chrome => "google-chrome",
+            Browser::Edge => "microsoft-edge",
+            Browser::Brave => "brave-browser",
+            Browser::Chromium => "chromium",
+            Browser::Vivaldi => "vivaldi",
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn wm_class(self) -> &'static str {
+        match self {
+            Browser::Chrome => "Google-chrome",
+            Browser::Edge => "Microsoft-edge",
+            Browser::Brave => "Brave-browser",
+            Browser::Chromium => "Chromium",
+            Browser::Vivaldi => "Vivaldi-stable",
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn app_support_dir_name(self) -> &'static str {
+        match self {
+            Browser::Chrome => "Google/Chrome",
+            Browser::Edge => "Microsoft Edge",
+            Browser::Brave => "BraveSoftware/Brave-Browser",
+            Browser::Chromium => "Chromium",
+            Browser::Vivaldi => "Vivaldi",
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn app_bundle_name(self) -> &'static str {
+        match self {
+            Browser::Chrome => "Google Chrome.app/Contents/MacOS/Google Chrome",
+            Browser::Edge => "Microsoft Edge.app/Contents/MacOS/Microsoft Edge",
+            Browser::Brave => "Brave Browser.app/Contents/MacOS/Brave Browser",
+            Browser::Chromium => "Chromium.app/Contents/MacOS/Chromium",
+            Browser::Vivaldi => "Vivaldi.app/Contents/MacOS/Vivaldi",
+        }
+    }
+}
+
 fn get_tags_path() -> PathBuf {
     let mut path = dirs::home_dir().unwrap();
     path.push(".chrome-launcher/tags.json");
     path
 }
 
-fn get_chrome_user_data_dir() -> Result<PathBuf, String> {
+fn get_chrome_user_data_dir(browser: Browser) -> Result<PathBuf, String> {
     #[cfg(target_os = "windows")]
     {
         let mut path =
             dirs::data_local_dir().ok_or("Cannot find local data dir")?;
-        path.push("Google/Chrome/User Data");
+        path.push(browser.app_dir_name());
+        path.push("User Data");
         Ok(path)
     }
 
@@ -88,26 +568,86 @@ fn get_chrome_user_data_dir() -> Result<PathBuf, String> {
     {
         let mut path =
             dirs::home_dir().ok_or("Cannot find home dir")?;
-        path.push(".config/google-chrome");
+        path.push(browser.config_dir_name());
+        Ok(path)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let mut path =
+            dirs::home_dir().ok_or("Cannot find home dir")?;
+        path.push("Library/Application Support");
+        path.push(browser.app_support_dir_name());
         Ok(path)
     }
 }
 
-fn get_chrome_executable() -> Result<PathBuf, String> {
+fn get_chrome_executable(browser: Browser) -> Result<PathBuf, String> {
     #[cfg(target_os = "windows")]
     {
-        Ok(PathBuf::from(
-            r"C:\Program Files\Google\Chrome\Application\chrome.exe",
-        ))
+        if let Some(path) = chrome_path_from_registry(browser) {
+            return Ok(path);
+        }
+
+        let candidates = [
+            format!(
+                r"C:\Program Files\{}\Application\{}",
+                browser.app_dir_name(),
+                browser.exe_name()
+            ),
+            format!(
+                r"C:\Program Files (x86)\{}\Application\{}",
+                browser.app_dir_name(),
+                browser.exe_name()
+            ),
+        ];
+        for candidate in &candidates {
+            let path = PathBuf::from(candidate);
+            if path.exists() {
+                return Ok(path);
+            }
+        }
+
+        if let Some(mut path) = dirs::data_local_dir() {
+            path.push(browser.app_dir_name());
+            path.push("Application");
+            path.push(browser.exe_name());
+            if path.exists() {
+                return Ok(path);
+            }
+        }
+
+        Ok(PathBuf::from(candidates.into_iter().next().unwrap()))
     }
 
     #[cfg(target_os = "linux")]
     {
-        Ok(PathBuf::from("google-chrome"))
+        Ok(PathBuf::from(browser.binary_name()))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let default_path =
+            PathBuf::from("/Applications").join(browser.app_bundle_name());
+        if default_path.exists() {
+            return Ok(default_path);
+        }
+
+        let mut user_path = dirs::home_dir().ok_or("Cannot find home dir")?;
+        user_path.push("Applications");
+        user_path.push(browser.app_bundle_name());
+        if user_path.exists() {
+            return Ok(user_path);
+        }
+
+        Ok(default_path)
     }
 }
 
 fn main() {
+    #[cfg(target_os = "linux")]
+    ORIGINAL_ENV.get_or_init(|| std::env::vars().collect());
+
     tauri::Builder::default()
         // .plugin(tauri_plugin_autostart::init(
         //     tauri_plugin_autostart::MacosLauncher::LaunchAgent,
@@ -122,9 +662,54 @@ fn main() {
         .invoke_handler(tauri::generate_handler![
             get_profiles,
             launch_profile,
+            launch_profile_with,
+            launch_profile_debug,
+            create_profile_shortcut,
             get_tags,
-            save_tags
+            save_tags,
+            get_launch_configs,
+            save_launch_configs
         ])
         .run(tauri::generate_context!())
         .expect("error");
 }
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn desktop_quote_wraps_plain_values() {
+        assert_eq!(desktop_quote("Profile 1"), "\"Profile 1\"");
+    }
+
+    #[test]
+    fn desktop_quote_escapes_reserved_characters_even_without_spaces() {
+        assert_eq!(desktop_quote("a;b"), "\"a;b\"");
+        assert_eq!(desktop_quote("$HOME"), "\"\\$HOME\"");
+        assert_eq!(desktop_quote("back`tick"), "\"back\\`tick\"");
+        assert_eq!(desktop_quote("quo\"te"), "\"quo\\\"te\"");
+        assert_eq!(desktop_quote(r"back\slash"), "\"back\\\\slash\"");
+    }
+
+    #[test]
+    fn dedup_lowest_priority_keeps_last_occurrence_in_place() {
+        let entries = vec![
+            "/a".to_string(),
+            "/b".to_string(),
+            "/a".to_string(),
+            "/c".to_string(),
+        ];
+
+        assert_eq!(
+            dedup_lowest_priority(entries),
+            vec!["/b".to_string(), "/a".to_string(), "/c".to_string()]
+        );
+    }
+
+    #[test]
+    fn dedup_lowest_priority_preserves_order_without_duplicates() {
+        let entries = vec!["/a".to_string(), "/b".to_string(), "/c".to_string()];
+        assert_eq!(dedup_lowest_priority(entries.clone()), entries);
+    }
+}